@@ -0,0 +1,166 @@
+//! Parses and evaluates the large-number notations people actually cite — Knuth's
+//! up-arrows and Conway's chained arrows — on top of [`crate::H`].
+
+use crate::H;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+const UP_ARROW: char = '↑';
+const CHAIN_ARROW: char = '→';
+
+/// An error produced by [`eval_str`] when the input isn't a recognized expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+	/// A numeral couldn't be parsed as a natural number.
+	Number(String),
+	/// A chain of 3 or more links ended in `0`, or had `0` as its second-to-last link while
+	/// being evaluated, which Conway's chained-arrow notation leaves undefined (unlike a `1`
+	/// there, which the notation defines away).
+	ZeroTail,
+}
+
+impl std::fmt::Display for ParseError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Number(s) => write!(f, "not a natural number: {s:?}"),
+			Self::ZeroTail => write!(f, "a chain of 3 or more links can't have 0 as either of its last two links"),
+		}
+	}
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_number(s: &str) -> Result<BigUint, ParseError> {
+	s.parse().map_err(|_| ParseError::Number(s.to_owned()))
+}
+
+/// Parses `a`, the arrow run, and `b` out of `s`, given the byte offset of the first arrow.
+fn eval_knuth(s: &str, split_at: usize) -> Result<BigUint, ParseError> {
+	let (a_str, rest) = s.split_at(split_at);
+
+	let k = rest.chars().take_while(|&c| c == UP_ARROW || c == '^').count();
+	let arrow_end = rest.char_indices().nth(k).map_or(rest.len(), |(i, _)| i);
+	let b_str = &rest[arrow_end..];
+
+	let a = parse_number(a_str)?;
+	let b = parse_number(b_str)?;
+
+	// k arrows/carets between two numbers is H(k + 2, a, b): a single arrow is plain
+	// exponentiation, i.e. H(3, a, b), since H(2, ..) is multiplication and H(1, ..) is addition
+	let order = BigUint::from(u32::try_from(k).unwrap_or(u32::MAX)) + 2_u8;
+	Ok(H(&order, a, &b))
+}
+
+/// Evaluates a Conway chain via its defining recurrence:
+///  - a chain of one link is itself,
+///  - a chain of two links `p → q` is `H(3, p, q)`, i.e. `p ^ q`,
+///  - a chain ending in `1` drops that trailing link,
+///  - `… → 1 → q` is `…` (the chain before the `1`),
+///  - `… → (p + 1) → (q + 1)` expands to `… → (… → p → (q + 1)) → q`.
+///
+/// A chain of 3+ links with `0` as either of its last two links isn't covered by the
+/// recurrence above (it's outside Conway's own notation, which is defined over positive
+/// integers) and is rejected rather than guessed at.
+fn eval_chain(chain: &[BigUint]) -> Result<BigUint, ParseError> {
+	match chain {
+		[] => unreachable!("eval_str never produces an empty chain"),
+		[x] => Ok(x.clone()),
+		[a, b] => Ok(H(&BigUint::from(3u8), a.clone(), b)),
+		_ => {
+			let (last, init) = chain.split_last().expect("checked above: len() >= 3");
+			if One::is_one(last) {
+				return eval_chain(init);
+			}
+			if Zero::is_zero(last) {
+				return Err(ParseError::ZeroTail);
+			}
+
+			let (p, prefix) = init.split_last().expect("len() >= 3, so init has >= 2 elements");
+			if One::is_one(p) {
+				return eval_chain(prefix);
+			}
+			if Zero::is_zero(p) {
+				return Err(ParseError::ZeroTail);
+			}
+			let q = last - 1_u8;
+
+			let mut inner = prefix.to_vec();
+			inner.push(p - 1_u8);
+			inner.push(last.clone());
+			let new_elem = eval_chain(&inner)?;
+
+			let mut next = prefix.to_vec();
+			next.push(new_elem);
+			next.push(q);
+			eval_chain(&next)
+		}
+	}
+}
+
+/// Parses and evaluates a Knuth up-arrow expression (`3^^^3`, `2↑↑↑↑3`) or a Conway
+/// chained-arrow expression (`a → b → c → …`), or just a bare natural number.
+pub fn eval_str(s: &str) -> Result<BigUint, ParseError> {
+	let s = s.trim();
+
+	if s.contains(CHAIN_ARROW) {
+		let chain = s
+			.split(CHAIN_ARROW)
+			.map(|part| parse_number(part.trim()))
+			.collect::<Result<Vec<_>, _>>()?;
+		return eval_chain(&chain);
+	}
+
+	if let Some(split_at) = s.find([UP_ARROW, '^']) {
+		return eval_knuth(s, split_at);
+	}
+
+	parse_number(s)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn knuth_arrows_match_h() {
+		// k arrows is H(k + 2, a, b)
+		assert_eq!(
+			eval_str("3^3"),
+			Ok(H(&BigUint::from(3u8), BigUint::from(3u8), &BigUint::from(3u8)))
+		);
+		assert_eq!(
+			eval_str("3^^3"),
+			Ok(H(&BigUint::from(4u8), BigUint::from(3u8), &BigUint::from(3u8)))
+		);
+		assert_eq!(
+			eval_str("2↑↑↑2"),
+			Ok(H(&BigUint::from(5u8), BigUint::from(2u8), &BigUint::from(2u8)))
+		);
+	}
+
+	#[test]
+	fn chains_match_knuth_arrows() {
+		// a chain of 2 links is plain exponentiation, i.e. a single arrow
+		assert_eq!(eval_str("3→3"), eval_str("3^3"));
+		// a 3-link chain p → q → 2 is p ↑↑ q (tetration), per Conway's own equivalence
+		assert_eq!(eval_str("3→3→2"), eval_str("3^^3"));
+	}
+
+	#[test]
+	fn bare_number() {
+		assert_eq!(eval_str("42"), Ok(BigUint::from(42u8)));
+	}
+
+	#[test]
+	fn rejects_garbage() {
+		assert!(eval_str("not a number").is_err());
+		assert!(eval_str("3^^x").is_err());
+	}
+
+	#[test]
+	fn rejects_zero_tailed_chain() {
+		assert_eq!(eval_str("3→3→0"), Err(ParseError::ZeroTail));
+		// a 2-link chain ending in 0 is just `p ^ 0`, which `H` already handles fine
+		assert_eq!(eval_str("3→0"), Ok(BigUint::from(1u8)));
+	}
+}
@@ -1,20 +1,155 @@
 #![deny(clippy::unwrap_used)]
 #![forbid(clippy::exit)]
 
+pub mod expr;
+
 use num_bigint::BigUint;
 use num_traits::{One, Zero};
 
+/// The arbitrary-precision integer operations actually used by [`H`], [`A`], [`Graham`], and
+/// [`big_pow`], abstracted so they can run against a different backend.
+///
+/// [`num_bigint::BigUint`] is the default; enable the `gmp` feature for a `rug`/GMP-backed
+/// implementation, which is substantially faster on the huge operands hyperoperations produce.
+pub trait HyperInt: Clone + PartialEq + PartialOrd {
+	fn zero() -> Self;
+	fn one() -> Self;
+	fn from_u8(n: u8) -> Self;
+	fn from_u32(n: u32) -> Self;
+
+	fn is_zero(&self) -> bool;
+	fn is_one(&self) -> bool;
+
+	/// Tests the `i`-th least-significant bit.
+	fn bit(&self, i: u64) -> bool;
+	/// Shifts right by one bit, in place.
+	fn shr1(&mut self);
+	/// The number of bits needed to represent `self` (`0` for `0`, matching [`BigUint::bits`]).
+	fn bits(&self) -> u64;
+
+	fn add(&self, rhs: &Self) -> Self;
+	fn add_small(&self, rhs: u8) -> Self;
+	fn sub_small(&self, rhs: u8) -> Self;
+	fn mul(&self, rhs: &Self) -> Self;
+
+	fn mul_assign(&mut self, rhs: &Self);
+	fn sub_assign_small(&mut self, rhs: u8);
+}
+
+impl HyperInt for BigUint {
+	fn zero() -> Self {
+		Zero::zero()
+	}
+	fn one() -> Self {
+		One::one()
+	}
+	fn from_u8(n: u8) -> Self {
+		BigUint::from(n)
+	}
+	fn from_u32(n: u32) -> Self {
+		BigUint::from(n)
+	}
+
+	fn is_zero(&self) -> bool {
+		Zero::is_zero(self)
+	}
+	fn is_one(&self) -> bool {
+		One::is_one(self)
+	}
+
+	fn bit(&self, i: u64) -> bool {
+		BigUint::bit(self, i)
+	}
+	fn shr1(&mut self) {
+		*self >>= 1_u8;
+	}
+	fn bits(&self) -> u64 {
+		BigUint::bits(self)
+	}
+
+	fn add(&self, rhs: &Self) -> Self {
+		self + rhs
+	}
+	fn add_small(&self, rhs: u8) -> Self {
+		self + rhs
+	}
+	fn sub_small(&self, rhs: u8) -> Self {
+		self - rhs
+	}
+	fn mul(&self, rhs: &Self) -> Self {
+		self * rhs
+	}
+
+	fn mul_assign(&mut self, rhs: &Self) {
+		*self *= rhs;
+	}
+	fn sub_assign_small(&mut self, rhs: u8) {
+		*self -= rhs;
+	}
+}
+
+/// `rug::Integer`, backed directly by GMP, for when `BigUint`'s schoolbook/Karatsuba
+/// multiplication is too slow for the operands at hand.
+#[cfg(feature = "gmp")]
+impl HyperInt for rug::Integer {
+	fn zero() -> Self {
+		rug::Integer::new()
+	}
+	fn one() -> Self {
+		rug::Integer::from(1_u8)
+	}
+	fn from_u8(n: u8) -> Self {
+		rug::Integer::from(n)
+	}
+	fn from_u32(n: u32) -> Self {
+		rug::Integer::from(n)
+	}
+
+	fn is_zero(&self) -> bool {
+		*self == 0
+	}
+	fn is_one(&self) -> bool {
+		*self == 1
+	}
+
+	fn bit(&self, i: u64) -> bool {
+		self.get_bit(i as u32)
+	}
+	fn shr1(&mut self) {
+		*self >>= 1_u32;
+	}
+	fn bits(&self) -> u64 {
+		u64::from(self.significant_bits())
+	}
+
+	fn add(&self, rhs: &Self) -> Self {
+		rug::Integer::from(self + rhs)
+	}
+	fn add_small(&self, rhs: u8) -> Self {
+		rug::Integer::from(self + rhs)
+	}
+	fn sub_small(&self, rhs: u8) -> Self {
+		rug::Integer::from(self - rhs)
+	}
+	fn mul(&self, rhs: &Self) -> Self {
+		rug::Integer::from(self * rhs)
+	}
+
+	fn mul_assign(&mut self, rhs: &Self) {
+		*self *= rhs;
+	}
+	fn sub_assign_small(&mut self, rhs: u8) {
+		*self -= rhs;
+	}
+}
+
 /// Calculates `b` ^ `e` (unbounded).
 ///
 /// It uses [binary exponentiation](https://en.wikipedia.org/wiki/Exponentiation_by_squaring) algorithm.
-///
-/// This helper is necessary because the `pow` method only supports `u32` as `exp`,
-/// but we need **truly arbitrary** precision, for mathematical correctness.
-fn big_pow(b: BigUint, e: &BigUint) -> BigUint {
-	if *e <= BigUint::from(core::u32::MAX) {
-		return b.pow(e.to_u32_digits()[0]);
+fn big_pow<T: HyperInt>(b: T, e: &T) -> T {
+	if e.is_zero() {
+		return T::one();
 	}
-
 	if b.is_zero() || b.is_one() {
 		return b;
 	}
@@ -22,52 +157,49 @@ fn big_pow(b: BigUint, e: &BigUint) -> BigUint {
 	let mut b = b;
 	let mut e = e.clone();
 
-	let mut out = BigUint::one();
-	loop {
+	let mut out = T::one();
+	while !e.is_zero() {
 		if e.bit(0) {
-			out *= &b;
+			out.mul_assign(&b);
 		}
-		e >>= 1;
-		b = &b * &b;
-
-		if e.is_one() {
-			drop(e);
-			break;
+		e.shr1();
+		if !e.is_zero() {
+			b = b.mul(&b);
 		}
 	}
-	out * b
+	out
 }
 
 /// Calculates the [Hyper-Operation function](https://en.wikipedia.org/wiki/Hyperoperation#Definition)
 ///
 /// `n` is "order" or "degree", `base` is `a`, `exp` is `b`
 #[allow(non_snake_case)]
-pub fn H(n: &BigUint, base: BigUint, exp: &BigUint) -> BigUint {
+pub fn H<T: HyperInt>(n: &T, base: T, exp: &T) -> T {
 	if n.is_zero() {
-		return exp + 1_u8;
+		return exp.add_small(1);
 	}
 	if n.is_one() {
-		return base + exp;
+		return base.add(exp);
 	}
 	{
-		let n0 = BigUint::zero();
-		let n1 = BigUint::one();
-		let n2 = &n1 + &n1;
+		let n0 = T::zero();
+		let n1 = T::one();
+		let n2 = n1.add(&n1);
 
 		if *n == n2 {
 			drop([n1, n2]);
-			return base * exp;
+			return base.mul(exp);
 		}
-		let n3 = n2.clone() + &n1;
+		let n3 = n2.add(&n1);
 		if *n == n3 {
 			drop([n1, n3]);
 			return big_pow(base, exp);
 		}
-		let n4 = n3 + &n1;
+		let n4 = n3.add(&n1);
 		debug_assert!(n >= &n4);
 
 		if base.is_zero() {
-			return if (exp % 2u8).is_zero() { n1 } else { n0 };
+			return if exp.bit(0) { n0 } else { n1 };
 		}
 		if base.is_one() {
 			return n1;
@@ -87,12 +219,12 @@ pub fn H(n: &BigUint, base: BigUint, exp: &BigUint) -> BigUint {
 		}
 	}
 
-	let n = n - 1_u8;
+	let n = n.sub_small(1);
 	let mut exp = exp.clone();
 
 	let mut out = base.clone();
 	loop {
-		exp -= 1_u8;
+		exp.sub_assign_small(1);
 		if exp.is_zero() {
 			break;
 		}
@@ -117,62 +249,656 @@ pub fn H(n: &BigUint, base: BigUint, exp: &BigUint) -> BigUint {
 ///
 /// For performance, this implementation is defined
 /// [like so](https://en.wikipedia.org/wiki/Ackermann_function#TRS,_based_on_hyperoperators)
-pub fn A(m: BigUint, n: BigUint) -> BigUint {
-	let n2 = BigUint::from(2u8);
-	H(&m, n2, &(n + 3_u8)) - 3_u8
+pub fn A<T: HyperInt>(m: T, n: T) -> T {
+	let n2 = T::from_u32(2);
+	H(&m, n2, &n.add_small(3)).sub_small(3)
 }
 
 #[allow(non_snake_case)]
 /// https://en.wikipedia.org/wiki/Graham%27s_number
-pub fn Graham(mut n: BigUint) -> BigUint {
-	let n3 = BigUint::from(3u8);
+pub fn Graham<T: HyperInt>(mut n: T) -> T {
+	let n3 = T::from_u8(3);
 
-	let mut x = BigUint::from(4u8);
+	let mut x = T::from_u8(4);
 	while !n.is_zero() {
-		n -= 1u8;
-		x = H(&(x + BigUint::from(2u8)), n3.clone(), &n3);
+		n.sub_assign_small(1);
+		x = H(&x.add_small(2), n3.clone(), &n3);
 	}
 	x
 }
 
+/// Computes the [Euler totient](https://en.wikipedia.org/wiki/Euler%27s_totient_function) `φ(n)`,
+/// via trial division.
+///
+/// This is only meant for the moduli [`H_mod`] deals with; it isn't a general-purpose factorizer.
+fn totient(n: &BigUint) -> BigUint {
+	if Zero::is_zero(n) {
+		return Zero::zero();
+	}
+
+	let mut out = n.clone();
+	let mut m = n.clone();
+	let mut p = BigUint::from(2u8);
+
+	while &p * &p <= m {
+		if Zero::is_zero(&(&m % &p)) {
+			while Zero::is_zero(&(&m % &p)) {
+				m /= &p;
+			}
+			out -= &out / &p;
+		}
+		p += 1_u8;
+	}
+	if m > One::one() {
+		out -= &out / &m;
+	}
+	out
+}
+
+/// Computes `H(order, base, height) mod modulus`, plus whether the true (unreduced) value
+/// is `>= modulus` (in which case the reduction below is only valid up to an additive
+/// `φ(modulus)`, per the generalized Euler / lifting-the-exponent rule).
+///
+/// `order` must be `>= 3`; lower orders never need the totient-chain trick, since they can't
+/// overflow fast enough for it to matter (see [`H_mod`]).
+fn tower_mod(
+	order: &BigUint,
+	base: &BigUint,
+	height: &BigUint,
+	modulus: &BigUint,
+) -> (BigUint, bool) {
+	if One::is_one(modulus) {
+		// nothing survives mod 1, and the true value is certainly huge enough for this to be valid
+		return (Zero::zero(), true);
+	}
+
+	let n3 = BigUint::from(3u8);
+	if *order == n3 {
+		if Zero::is_zero(height) {
+			// matches `big_pow`'s own shortcut: `base ^ 0 == 1`, even when `base == 0`
+			return (<BigUint as One>::one() % modulus, false);
+		}
+		if Zero::is_zero(base) || One::is_one(base) {
+			return (base % modulus, false);
+		}
+		if One::is_one(height) {
+			return (base % modulus, base >= modulus);
+		}
+
+		// `height * (base.bits() - 1)` lower-bounds log2(base ^ height); once that reaches
+		// `modulus.bits()` the value is certainly `>= modulus`, otherwise it's small enough
+		// (under twice `modulus.bits()` bits, as `base >= 2`) to just materialize exactly
+		let bits_lower = height * BigUint::from(base.bits() - 1);
+		return if bits_lower < BigUint::from(modulus.bits()) {
+			let exact = big_pow(base.clone(), height);
+			(&exact % modulus, exact >= *modulus)
+		} else {
+			(base.modpow(height, modulus), true)
+		};
+	}
+
+	if Zero::is_zero(base) {
+		let v = if Zero::is_zero(&(height % 2u8)) { 1_u8 } else { 0_u8 };
+		return (BigUint::from(v), false);
+	}
+	if One::is_one(base) {
+		return (<BigUint as One>::one(), false);
+	}
+	if Zero::is_zero(height) {
+		return (<BigUint as One>::one(), false);
+	}
+	if One::is_one(height) {
+		return (base % modulus, base >= modulus);
+	}
+
+	// H(order, base, height) = H(order - 1, base, H(order, base, height - 1));
+	// the inner call only needs its result mod φ(modulus), since it's about to become
+	// an exponent-ish argument one level down
+	let phi = totient(modulus);
+	let (inner_val, inner_big) = tower_mod(order, base, &(height - 1_u8), &phi);
+	let reduced_height = if inner_big { inner_val + &phi } else { inner_val };
+
+	tower_mod(&(order - 1_u8), base, &reduced_height, modulus)
+}
+
+/// Calculates `H(n, base, exp) mod modulus`, without ever materializing the (possibly
+/// unfathomably large) unreduced value.
+///
+/// For `n <= 3` this is plain modular arithmetic. For `n >= 4`, `base ^ height`-shaped
+/// sub-problems are reduced via the totient chain `modulus, φ(modulus), φ(φ(modulus)), …`,
+/// which reaches `1` in `O(log modulus)` steps: `a ^ k ≡ a ^ (k mod φ(m) + φ(m)) (mod m)`
+/// whenever `k >= log2(m)`, even when `gcd(a, m) != 1` (unlike plain Euler's theorem).
+#[allow(non_snake_case)]
+pub fn H_mod(n: &BigUint, base: BigUint, exp: &BigUint, modulus: &BigUint) -> BigUint {
+	debug_assert!(!Zero::is_zero(modulus), "modulo by zero is undefined");
+	if One::is_one(modulus) {
+		return Zero::zero();
+	}
+
+	if Zero::is_zero(n) {
+		return (exp + 1_u8) % modulus;
+	}
+	if One::is_one(n) {
+		return (base + exp) % modulus;
+	}
+	let n2 = BigUint::from(2u8);
+	if *n == n2 {
+		return (base * exp) % modulus;
+	}
+
+	tower_mod(n, &base, exp, modulus).0
+}
+
+/// The result of inverting a hyperoperation: either an exact integer match, or the two
+/// consecutive integers the true (non-integer) inverse falls strictly between.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HyperInv {
+	Exact(BigUint),
+	Between(BigUint, BigUint),
+}
+
+/// Binary-searches for the largest `x >= lo` with `f(x) <= result`, given `f` is
+/// monotonically non-decreasing on `x >= lo`. Brackets by doubling before bisecting.
+fn monotonic_inverse(lo: BigUint, result: &BigUint, f: impl Fn(&BigUint) -> BigUint) -> HyperInv {
+	if &f(&lo) > result {
+		return HyperInv::Between(&lo - 1_u8, lo);
+	}
+
+	let mut hi = &lo + 1_u8;
+	while &f(&hi) <= result {
+		hi *= 2_u8;
+	}
+
+	let mut lo = lo;
+	while &hi - &lo > One::one() {
+		let mid = (&lo + &hi) >> 1_u32;
+		if &f(&mid) <= result {
+			lo = mid;
+		} else {
+			hi = mid;
+		}
+	}
+
+	if &f(&lo) == result {
+		HyperInv::Exact(lo)
+	} else {
+		HyperInv::Between(lo, hi)
+	}
+}
+
+/// Recovers `base` from `H(n, base, exp) == result` (the "super-root").
+///
+/// `n` must be `>= 3`. `exp == 0` has no unique inverse (`H(n, base, 0) == 1` for every
+/// `base`), so it's out of scope here.
+///
+/// For `n == 3` this is an integer `exp`-th root, via [`BigUint::nth_root`] (or
+/// [`BigUint::sqrt`] when `exp == 2`). For `n >= 4`, `H` is monotonically increasing in
+/// `base` once `base >= 2`, so the root is found by binary search: double an upper bound
+/// until `H` overshoots `result`, then bisect.
+#[allow(non_snake_case)]
+pub fn H_inv_base(n: &BigUint, result: &BigUint, exp: &BigUint) -> HyperInv {
+	let n3 = BigUint::from(3u8);
+	debug_assert!(*n >= n3, "super-root is only defined for order >= 3");
+	debug_assert!(
+		!Zero::is_zero(exp),
+		"H(n, base, 0) == 1 for every base, so it has no unique inverse"
+	);
+
+	if One::is_one(exp) {
+		// H(n, base, 1) == base, for every n >= 3
+		return HyperInv::Exact(result.clone());
+	}
+	if H(n, Zero::zero(), exp) == *result {
+		return HyperInv::Exact(Zero::zero());
+	}
+	if H(n, One::one(), exp) == *result {
+		return HyperInv::Exact(One::one());
+	}
+
+	if *n == n3 {
+		let exp_u32 = exp.to_u32_digits().first().copied().unwrap_or(1);
+		let root = if exp_u32 == 2 {
+			result.sqrt()
+		} else {
+			result.nth_root(exp_u32)
+		};
+		return if big_pow(root.clone(), exp) == *result {
+			HyperInv::Exact(root)
+		} else {
+			HyperInv::Between(root.clone(), root + 1_u8)
+		};
+	}
+
+	monotonic_inverse(BigUint::from(2u8), result, |base| H(n, base.clone(), exp))
+}
+
+/// Recovers the largest `exp` with `H(n, base, exp) <= result` (the "super-logarithm"),
+/// i.e. whether `H(n, base, exp) == result` exactly, or `result` falls strictly between
+/// two consecutive towers.
+///
+/// `n` must be `>= 3` and `base` is assumed `>= 2`, for the same monotonicity reason as
+/// [`H_inv_base`]; `H` isn't monotonic in `exp` for `base` of `0` or `1`.
+#[allow(non_snake_case)]
+pub fn H_inv_exp(n: &BigUint, base: &BigUint, result: &BigUint) -> HyperInv {
+	let n3 = BigUint::from(3u8);
+	debug_assert!(*n >= n3, "super-logarithm is only defined for order >= 3");
+	debug_assert!(*base >= BigUint::from(2u8), "H isn't monotonic in exp below base == 2");
+	debug_assert!(!Zero::is_zero(result), "H(n, base, _) >= 1 always, for base >= 2");
+
+	if One::is_one(result) {
+		// H(n, base, 0) == 1, for every base
+		return HyperInv::Exact(Zero::zero());
+	}
+	if base == result {
+		// H(n, base, 1) == base
+		return HyperInv::Exact(One::one());
+	}
+
+	monotonic_inverse(Zero::zero(), result, |exp| H(n, base.clone(), exp))
+}
+
+/// Converts a bit-length upper bound into an approximate base-10 digit count, via the
+/// standard `log10(2) ≈ 1233 / 4096` rational bound — integer arithmetic only, so this holds
+/// even under `forbid(float_arithmetic)`.
+fn digits_from_bits(bits: &BigUint) -> BigUint {
+	bits * 1233_u16 / 4096_u16 + 1_u8
+}
+
+/// An approximate size for a hyperoperation result, without materializing the value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SizeEstimate {
+	/// A base-10 digit count: exact at orders `0` and `1` (cheap to materialize exactly),
+	/// approximate from order `2` up.
+	Digits(BigUint),
+	/// A power tower `base ↑^arrows base ↑^arrows … ↑^arrows base`, `height` copies of `base`
+	/// tall (so `order == arrows + 3`: one order above [`expr`]'s single `base ↑^arrows height`,
+	/// where `order == arrows + 2`) — too tall to even
+	/// approximate a digit count for.
+	Tower {
+		base: BigUint,
+		arrows: u32,
+		height: BigUint,
+	},
+}
+
+impl std::fmt::Display for SizeEstimate {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Digits(n) => write!(f, "~{n} decimal digits"),
+			Self::Tower { base, arrows, height } => {
+				let arrow = "↑".repeat(*arrows as usize);
+				write!(f, "{base}{arrow}{base}{arrow}…{arrow}{base} ({height} copies of {base})")
+			}
+		}
+	}
+}
+
+/// Estimates the size of `H(n, base, exp)` without materializing it, so the caller can
+/// decide whether to even attempt the computation.
+///
+/// Orders `0` and `1` (successor, addition) are cheap to compute outright, so their digit
+/// count is exact. Orders `2` and `3` (multiplication, exponentiation) give an approximate
+/// decimal digit count, computed from operand bit lengths rather than the (possibly
+/// unfathomably large) value itself. For order `>= 4` the result is a power tower, too tall
+/// to usefully approximate a digit count for.
+#[allow(non_snake_case)]
+pub fn H_size(n: &BigUint, base: &BigUint, exp: &BigUint) -> SizeEstimate {
+	if Zero::is_zero(n) {
+		// exp + 1 is cheap to materialize outright, so just count its actual digits
+		let digits = (exp + 1_u8).to_string().len();
+		return SizeEstimate::Digits(BigUint::from(digits));
+	}
+	if One::is_one(n) {
+		// base + exp is likewise cheap to materialize outright
+		let digits = (base + exp).to_string().len();
+		return SizeEstimate::Digits(BigUint::from(digits));
+	}
+	let n2 = BigUint::from(2u8);
+	if *n == n2 {
+		// digits(a * b) ~= digits(a) + digits(b)
+		let bits = BigUint::from(base.bits()) + BigUint::from(exp.bits());
+		return SizeEstimate::Digits(digits_from_bits(&bits));
+	}
+	let n3 = BigUint::from(3u8);
+	if *n == n3 {
+		if Zero::is_zero(base) || One::is_one(base) {
+			return SizeEstimate::Digits(One::one());
+		}
+		// digits(base ^ exp) ~= exp * log10(base); bound log10(base) via base's bit length,
+		// the same trick tower_mod uses to classify "exact vs. big"
+		let bits = exp * BigUint::from(base.bits());
+		return SizeEstimate::Digits(digits_from_bits(&bits));
+	}
+
+	let arrows = (n - 3_u8).to_u32_digits().first().copied().unwrap_or(u32::MAX);
+	SizeEstimate::Tower { base: base.clone(), arrows, height: exp.clone() }
+}
+
+/// An error from [`H_bounded`]: the computation was aborted because an intermediate value's
+/// bit length would have exceeded the requested budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl std::fmt::Display for Overflow {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "result exceeds the requested bit budget")
+	}
+}
+
+impl std::error::Error for Overflow {}
+
+/// Like [`H`], but aborts with [`Overflow`] as soon as an intermediate value's bit length
+/// would exceed `max_bits`, instead of running the allocation out of memory like `H` does.
+///
+/// Generic over [`HyperInt`] (not just [`BigUint`]), so the `gmp` feature's `rug::Integer`
+/// backend gets the same bit-budget safety net as the default one.
+#[allow(non_snake_case)]
+pub fn H_bounded<T: HyperInt>(n: &T, base: T, exp: &T, max_bits: u64) -> Result<T, Overflow> {
+	let check = |v: T| if v.bits() > max_bits { Err(Overflow) } else { Ok(v) };
+
+	if n.is_zero() {
+		return check(exp.add_small(1));
+	}
+	if n.is_one() {
+		return check(base.add(exp));
+	}
+	let n2 = T::from_u8(2);
+	if *n == n2 {
+		return check(base.mul(exp));
+	}
+	let n3 = T::from_u8(3);
+	if *n == n3 {
+		if base.is_zero() || base.is_one() {
+			return check(big_pow(base, exp));
+		}
+		// `base ^ exp` has at least `exp * (base_bits - 1) + 1` bits; past the budget that alone
+		// settles it, otherwise the exact value (under twice that many bits) is computed and checked
+		let base_bits = u32::try_from(base.bits()).unwrap_or(u32::MAX);
+		let bits_lower = exp.mul(&T::from_u32(base_bits - 1)).add_small(1);
+		if bits_lower > T::from_u32(u32::try_from(max_bits).unwrap_or(u32::MAX)) {
+			return Err(Overflow);
+		}
+		return check(big_pow(base, exp));
+	}
+	let n4 = T::from_u8(4);
+	debug_assert!(*n >= n4);
+
+	if base.is_zero() {
+		return check(if exp.bit(0) { T::zero() } else { T::one() });
+	}
+	if base.is_one() {
+		return check(T::one());
+	}
+	if exp.is_zero() {
+		return check(T::one());
+	}
+	if exp.is_one() {
+		return check(base);
+	}
+
+	let n_minus_1 = n.sub_small(1);
+	let mut remaining = exp.clone();
+	let mut out = base.clone();
+	loop {
+		remaining.sub_assign_small(1);
+		if remaining.is_zero() {
+			break;
+		}
+		out = H_bounded(&n_minus_1, base.clone(), &out, max_bits)?;
+	}
+	check(out)
+}
+
 #[cfg(test)]
 mod tests {
 	#[allow(clippy::wildcard_imports)]
 	use super::*;
 	use num_bigint::BigUint;
-	use num_traits::One;
+	use num_traits::{One, Zero};
+
+	// The fixed tables that used to live here only ever checked the values the original
+	// author happened to type in. The checks below assert the defining recurrence and the
+	// known closed forms directly, swept over small bounded inputs, so a regression
+	// anywhere in `H`/`A`/`H_mod` gets caught rather than just a regression at those exact
+	// points. (These are exhaustive sweeps over small ranges, not `arbitrary`-generated
+	// cases or a `cargo-fuzz` target.)
+	// Inputs stay small — `n <= 4`, operands single digits from exponentiation up — since
+	// anything larger blows up super-exponentially long before it'd tell us anything new.
 
 	#[test]
-	fn table_cmp() {
-		let mut m = BigUint::zero();
-		for n in 0..core::u8::MAX {
-			assert_eq!(A(m.clone(), BigUint::from(n)), BigUint::from(n + 1));
+	fn base_case_equalities() {
+		for a in 0_u32..8 {
+			for b in 0_u32..8 {
+				let (a, b) = (BigUint::from(a), BigUint::from(b));
+				assert_eq!(H(&Zero::zero(), a.clone(), &b), &b + 1_u8);
+				assert_eq!(H(&One::one(), a.clone(), &b), &a + &b);
+				assert_eq!(H(&BigUint::from(2u8), a.clone(), &b), &a * &b);
+				assert_eq!(H(&BigUint::from(3u8), a.clone(), &b), big_pow(a, &b));
+			}
 		}
+	}
+
+	#[test]
+	fn recurrence_holds() {
+		// H(n + 1, a, b) = H(n, a, H(n + 1, a, b - 1)), for b >= 1. Kept to n <= 2 (so
+		// n + 1 <= 3) so the inner H(n + 1, ..) never lands on tetration-of-tetration.
+		for n in 0_u8..3 {
+			let n = BigUint::from(n);
+			let n_plus_1 = &n + 1_u8;
+			for a in 1_u32..5 {
+				for b in 1_u32..5 {
+					let a = BigUint::from(a);
+					let b = BigUint::from(b);
 
-		m = BigUint::one();
-		for n in 0..(core::u8::MAX - 1) {
-			assert_eq!(A(m.clone(), BigUint::from(n)), BigUint::from(n + 2));
+					let lhs = H(&n_plus_1, a.clone(), &b);
+					let inner = H(&n_plus_1, a.clone(), &(&b - 1_u8));
+					let rhs = H(&n, a, &inner);
+					assert_eq!(lhs, rhs, "H({n_plus_1}, a, b) recurrence");
+				}
+			}
 		}
+	}
 
-		m = BigUint::from(2u8);
-		for n in 0..(core::u8::MAX >> 2) {
-			assert_eq!(A(m.clone(), BigUint::from(n)), BigUint::from(2 * n + 3));
+	#[test]
+	fn ackermann_bridge() {
+		// A(m, n) = H(m, 2, n + 3) - 3
+		for m in 0_u8..4 {
+			for n in 0_u8..6 {
+				let (m_big, n_big) = (BigUint::from(m), BigUint::from(n));
+				let lhs = A(m_big.clone(), n_big.clone());
+				let rhs = H(&m_big, BigUint::from(2u8), &(n_big + 3_u8)) - 3_u8;
+				assert_eq!(lhs, rhs, "A({m}, {n})");
+			}
 		}
+	}
 
-		m = BigUint::from(3u8);
-		for n in 0..0x10u8 {
-			assert_eq!(
-				A(m.clone(), BigUint::from(n)),
-				BigUint::from(2_u32.pow(u32::from(n) + 3) - 3)
-			);
+	#[test]
+	fn known_closed_forms() {
+		let m: BigUint = Zero::zero();
+		for n in 0..u8::MAX {
+			assert_eq!(A(m.clone(), BigUint::from(n)), BigUint::from(n + 1));
 		}
 
-		m = BigUint::from(4u8);
-		assert_eq!(A(m.clone(), BigUint::zero()), BigUint::from(13_u8));
-		assert_eq!(A(m.clone(), BigUint::one()), BigUint::from(0xFFFD_u16));
+		let m = BigUint::from(4u8);
+		assert_eq!(A(m.clone(), Zero::zero()), BigUint::from(13_u8));
+		assert_eq!(A(m.clone(), One::one()), BigUint::from(0xFFFD_u16));
 		assert_eq!(
-			A(m.clone(), BigUint::from(2u8)),
-			(BigUint::one() << 0x1_00_00) - 3_u8
+			A(m, BigUint::from(2u8)),
+			(<BigUint as One>::one() << 0x1_00_00) - 3_u8
 		);
 	}
+
+	#[test]
+	fn h_mod_matches_h() {
+		for modulus in 2_u32..12 {
+			let modulus = BigUint::from(modulus);
+
+			for n in 0_u8..3 {
+				let n = BigUint::from(n);
+				for base in 0_u32..6 {
+					for exp in 0_u32..6 {
+						let expected = H(&n, BigUint::from(base), &BigUint::from(exp)) % &modulus;
+						let actual = H_mod(&n, BigUint::from(base), &BigUint::from(exp), &modulus);
+						assert_eq!(expected, actual, "H({n}, {base}, {exp}) mod {modulus}");
+					}
+				}
+			}
+
+			let n3 = BigUint::from(3u8);
+			for base in 0_u32..6 {
+				for exp in 0_u32..6 {
+					let expected = H(&n3, BigUint::from(base), &BigUint::from(exp)) % &modulus;
+					let actual = H_mod(&n3, BigUint::from(base), &BigUint::from(exp), &modulus);
+					assert_eq!(expected, actual, "H({n3}, {base}, {exp}) mod {modulus}");
+				}
+			}
+
+			// order 4: kept shallow enough that the exact tower is still materializable
+			let n4 = BigUint::from(4u8);
+			for base in 0_u32..4 {
+				for exp in 0_u32..4 {
+					let expected = H(&n4, BigUint::from(base), &BigUint::from(exp)) % &modulus;
+					let actual = H_mod(&n4, BigUint::from(base), &BigUint::from(exp), &modulus);
+					assert_eq!(expected, actual, "H({n4}, {base}, {exp}) mod {modulus}");
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn hyper_inv_roundtrip() {
+		// order 3: exact values stay small enough for any base/exp in this range
+		for n in [3_u8, 4] {
+			let n = BigUint::from(n);
+			// order 4: kept shallow enough that the exact tower is still materializable
+			let max_exp = if n == BigUint::from(4u8) { 3_u32 } else { 6 };
+
+			for base in 2_u32..6 {
+				let base = BigUint::from(base);
+				for exp in 1_u32..max_exp {
+					let exp = BigUint::from(exp);
+					let result = H(&n, base.clone(), &exp);
+
+					assert_eq!(
+						H_inv_base(&n, &result, &exp),
+						HyperInv::Exact(base.clone()),
+						"H_inv_base({n}, {result}, {exp})"
+					);
+
+					// H_inv_exp's bracket-doubling evaluates H at up to twice the true
+					// exponent; at order 4 that's a tower one level taller, which explodes
+					// the moment the true height is 2 or more. Only order 3 and height 1
+					// are exercised here.
+					if n == BigUint::from(3u8) || One::is_one(&exp) {
+						assert_eq!(
+							H_inv_exp(&n, &base, &result),
+							HyperInv::Exact(exp.clone()),
+							"H_inv_exp({n}, {base}, {result})"
+						);
+					}
+
+					// one past an exact tower: no integer base/exp reaches it exactly.
+					// only checked at order 3: at order 4, the search for the next height up
+					// would have to materialize an un-computably large tower.
+					if n == BigUint::from(3u8) {
+						let overshoot = &result + 1_u8;
+						match H_inv_exp(&n, &base, &overshoot) {
+							HyperInv::Exact(got) => assert_eq!(
+								H(&n, base.clone(), &got),
+								overshoot,
+								"H_inv_exp({n}, {base}, {overshoot}) claimed Exact({got})"
+							),
+							HyperInv::Between(lo, hi) => {
+								assert_eq!(lo, exp, "H_inv_exp({n}, {base}, {overshoot})");
+								assert_eq!(hi, &exp + 1_u8, "H_inv_exp({n}, {base}, {overshoot})");
+							}
+						}
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn h_size_matches_exact_digits() {
+		for n in 0_u8..4 {
+			let n = BigUint::from(n);
+			for base in 2_u32..6 {
+				for exp in 2_u32..6 {
+					let result = H(&n, BigUint::from(base), &BigUint::from(exp));
+					let expected_digits = BigUint::from(result.to_string().len());
+
+					let SizeEstimate::Digits(got_digits) = H_size(&n, &BigUint::from(base), &BigUint::from(exp))
+					else {
+						panic!("order {n} should estimate a digit count, not a tower");
+					};
+
+					// a log10(2) ≈ 1233/4096 rational bound is approximate, not exact: allow
+					// it to land within a couple digits of the real count
+					let diff = if got_digits > expected_digits {
+						&got_digits - &expected_digits
+					} else {
+						&expected_digits - &got_digits
+					};
+					assert!(
+						diff <= BigUint::from(2u8),
+						"H_size({n}, {base}, {exp}) = {got_digits} digits, actual = {expected_digits}"
+					);
+				}
+			}
+		}
+
+		// order >= 4 is a tower, not a digit count
+		assert!(matches!(
+			H_size(&BigUint::from(4u8), &BigUint::from(3u8), &BigUint::from(3u8)),
+			SizeEstimate::Tower { .. }
+		));
+	}
+
+	#[test]
+	fn h_size_is_exact_at_orders_0_and_1() {
+		// orders 0 and 1 are cheap to materialize outright, so the bit-length approximation
+		// the orders above them rely on shouldn't apply here at all
+		for n in 0_u8..2 {
+			let n = BigUint::from(n);
+			for base in 0_u32..40 {
+				for exp in 0_u32..40 {
+					let (base, exp) = (BigUint::from(base), BigUint::from(exp));
+					let expected = BigUint::from(H(&n, base.clone(), &exp).to_string().len());
+
+					let SizeEstimate::Digits(got) = H_size(&n, &base, &exp) else {
+						panic!("order {n} should estimate a digit count, not a tower");
+					};
+					assert_eq!(got, expected, "H_size({n}, {base}, {exp})");
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn h_bounded_matches_h_within_budget_and_overflows_past_it() {
+		for n in 0_u8..5 {
+			let n = BigUint::from(n);
+			for base in 2_u32..6 {
+				for exp in 2_u32..4 {
+					let base = BigUint::from(base);
+					let exp = BigUint::from(exp);
+					let expected = H(&n, base.clone(), &exp);
+
+					// no intermediate outgrows the final result here, so `expected.bits()`
+					// alone would do; the extra headroom keeps this check from hinging on that
+					assert_eq!(
+						H_bounded(&n, base.clone(), &exp, expected.bits() * 2 + 16),
+						Ok(expected.clone()),
+						"H_bounded({n}, {base}, {exp}) with generous headroom"
+					);
+					assert_eq!(
+						H_bounded(&n, base, &exp, 0),
+						Err(Overflow),
+						"H_bounded({n}, {exp}) with no budget at all"
+					);
+				}
+			}
+		}
+	}
 }
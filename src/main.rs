@@ -5,7 +5,7 @@
 	clippy::pedantic,
 	clippy::nursery,
 	clippy::shadow_unrelated,
-	clippy::string_to_string,
+	clippy::implicit_clone,
 	clippy::decimal_literal_representation,
 	clippy::unseparated_literal_suffix,
 	clippy::empty_structs_with_brackets,
@@ -27,31 +27,64 @@
 fn print_help() {
 	println!(
 		"usage: hyper_op n base exp\n\
-		where all are Natural decimal numerals\n"
+		where all are Natural decimal numerals\n\
+		\n\
+		or:    hyper_op expr\n\
+		where `expr` is a Knuth up-arrow (`3^^^3`, `2↑↑↑↑3`) or\n\
+		Conway chained-arrow (`a → b → c`) expression\n"
 	);
 }
 
 fn main() {
-	use hyper_op::H;
+	use hyper_op::{expr, H_bounded, H_size};
 	use num_bigint::BigUint;
 	use std::str::FromStr;
 
+	// picked at compile time: GMP is much faster on the huge operands hyperoperations produce,
+	// but dragging in its build dependencies isn't worth it unless asked for
+	#[cfg(feature = "gmp")]
+	type Int = rug::Integer;
+	#[cfg(not(feature = "gmp"))]
+	type Int = num_bigint::BigUint;
+
+	// big enough for everyday hyperoperation results, small enough that overshooting it fails
+	// fast via `H_bounded`'s own check (reported with `H_size`'s estimate), instead of running
+	// the allocator out of memory
+	const MAX_BITS: u64 = 1 << 24;
+
 	let args: Vec<String> = std::env::args().skip(1).take(3).collect();
 
 	if args.is_empty() {
 		return print_help();
-	};
+	}
 	let a0 = &args[0].to_ascii_lowercase();
 	if a0 == "help" || a0 == "?" {
 		return print_help();
-	};
+	}
+
+	if args.len() == 1 {
+		return match expr::eval_str(&args[0]) {
+			Ok(result) => println!("{result}"),
+			Err(e) => eprintln!("{e}"),
+		};
+	}
+
+	// `H_size` only understands `BigUint`, so estimate with that regardless of which backend
+	// ends up doing the real computation
+	let estimate = H_size(
+		&BigUint::from_str(a0).expect("Cannot parse `n`"),
+		&BigUint::from_str(&args[1]).expect("Cannot parse `base`"),
+		&BigUint::from_str(&args[2]).expect("Cannot parse `exp`"),
+	);
 
-	let a0 = BigUint::from_str(a0).expect("Cannot parse `n`");
-	let a1 = BigUint::from_str(&args[1]).expect("Cannot parse `base`");
-	let a2 = BigUint::from_str(&args[2]).expect("Cannot parse `exp`");
+	let n = Int::from_str(a0).expect("Cannot parse `n`");
+	let base = Int::from_str(&args[1]).expect("Cannot parse `base`");
+	let exp = Int::from_str(&args[2]).expect("Cannot parse `exp`");
 
-	// we need as much memory as possible for the next step
 	drop(args);
 
-	println!("{}", H(&a0, a1, &a2));
+	match H_bounded(&n, base, &exp, MAX_BITS) {
+		Ok(result) => println!("{result}"),
+		Err(e) => eprintln!("{e} (estimate: {estimate})"),
+	}
 }